@@ -0,0 +1,244 @@
+use std::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+/*
+ * A Bad but Safe Doubly-Linked Deque
+ * - Shared, mutable ownership of nodes via Rc<RefCell<Node<T>>>
+ * - Supports push/pop at both ends
+ */
+
+type NodeRc<T> = Rc<RefCell<Node<T>>>;
+/// Type representing valid states of list nodes.
+type Link<T> = Option<NodeRc<T>>;
+
+/// Struct representing the Linked List
+/// that keeps track of the head and tail pointers.
+pub struct List<T> {
+    head: Link<T>,
+    tail: Link<T>,
+}
+
+/// Struct representing nodes of Linked List
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+    prev: Link<T>,
+}
+
+impl<T> Node<T> {
+    /// Create a new Node wrapped in Rc<RefCell<_>>, with no neighbours
+    fn new(elem: T) -> NodeRc<T> {
+        Rc::new(RefCell::new(Node {
+            elem: elem,
+            next: None,
+            prev: None,
+        }))
+    }
+}
+
+impl<T> List<T> {
+    /// Create a new List
+    pub fn new() -> Self {
+        List {
+            head: None,
+            tail: None,
+        }
+    }
+
+    /// Inserts value at the front of the Deque
+    pub fn push_front(&mut self, elem: T) {
+        let new_head: NodeRc<T> = Node::new(elem);
+
+        match self.head.take() {
+            Some(old_head) => {
+                // Stitch the new node in front of the existing head.
+                old_head.borrow_mut().prev = Some(new_head.clone());
+                new_head.borrow_mut().next = Some(old_head);
+                self.head = Some(new_head);
+            }
+            None => {
+                // List was empty, new node becomes head and tail.
+                self.tail = Some(new_head.clone());
+                self.head = Some(new_head);
+            }
+        }
+    }
+
+    /// Remove the first value from the front of the Deque and return it
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|old_head: NodeRc<T>| {
+            match old_head.borrow_mut().next.take() {
+                Some(new_head) => {
+                    // Detach the old head from the new head.
+                    new_head.borrow_mut().prev.take();
+                    self.head = Some(new_head);
+                }
+                None => {
+                    // List is now empty.
+                    self.tail.take();
+                }
+            }
+
+            Rc::try_unwrap(old_head).ok().unwrap().into_inner().elem
+        })
+    }
+
+    /// Inserts value at the back of the Deque
+    pub fn push_back(&mut self, elem: T) {
+        let new_tail: NodeRc<T> = Node::new(elem);
+
+        match self.tail.take() {
+            Some(old_tail) => {
+                // Stitch the new node behind the existing tail.
+                old_tail.borrow_mut().next = Some(new_tail.clone());
+                new_tail.borrow_mut().prev = Some(old_tail);
+                self.tail = Some(new_tail);
+            }
+            None => {
+                // List was empty, new node becomes head and tail.
+                self.head = Some(new_tail.clone());
+                self.tail = Some(new_tail);
+            }
+        }
+    }
+
+    /// Remove the last value from the back of the Deque and return it
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.take().map(|old_tail: NodeRc<T>| {
+            match old_tail.borrow_mut().prev.take() {
+                Some(new_tail) => {
+                    // Detach the old tail from the new tail.
+                    new_tail.borrow_mut().next.take();
+                    self.tail = Some(new_tail);
+                }
+                None => {
+                    // List is now empty.
+                    self.head.take();
+                }
+            }
+
+            Rc::try_unwrap(old_tail).ok().unwrap().into_inner().elem
+        })
+    }
+
+    /// Retrieve a reference to the head element of the Deque
+    pub fn peek_front(&self) -> Option<Ref<'_, T>> {
+        self.head.as_ref().map(|node: &NodeRc<T>| {
+            Ref::map(node.borrow(), |node: &Node<T>| &node.elem)
+        })
+    }
+
+    /// Retrieve a reference to the tail element of the Deque
+    pub fn peek_back(&self) -> Option<Ref<'_, T>> {
+        self.tail.as_ref().map(|node: &NodeRc<T>| {
+            Ref::map(node.borrow(), |node: &Node<T>| &node.elem)
+        })
+    }
+
+    /// Retrieve a mutable reference to the head element of the Deque
+    pub fn peek_front_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.head.as_ref().map(|node: &NodeRc<T>| {
+            RefMut::map(node.borrow_mut(), |node: &mut Node<T>| &mut node.elem)
+        })
+    }
+
+    /// Retrieve a mutable reference to the tail element of the Deque
+    pub fn peek_back_mut(&mut self) -> Option<RefMut<'_, T>> {
+        self.tail.as_ref().map(|node: &NodeRc<T>| {
+            RefMut::map(node.borrow_mut(), |node: &mut Node<T>| &mut node.elem)
+        })
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        // Pop from the front until the list is empty. The strong `next`
+        // and `prev` Rc links form reference cycles, so without this the
+        // nodes would never be freed.
+        while self.pop_front().is_some() {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+
+    /// Testing basic push/pop at both ends
+    #[test]
+    fn basics() {
+        let mut list: List<i32> = List::new();
+
+        // Check empty list behaves right
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        // Push onto the front
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(2));
+
+        // Push additional data
+        list.push_front(5);
+        list.push_front(4);
+
+        assert_eq!(list.pop_front(), Some(4));
+        assert_eq!(list.pop_front(), Some(5));
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    /// Testing interaction between both ends, including emptying transitions
+    #[test]
+    fn both_ends() {
+        let mut list: List<i32> = List::new();
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_front(0);
+
+        // List is now [0, 1, 2]
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), Some(1));
+
+        // Empty again
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        // Make sure pushing after emptying still works from both ends
+        list.push_back(10);
+        list.push_front(20);
+        assert_eq!(list.pop_back(), Some(10));
+        assert_eq!(list.pop_back(), Some(20));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    /// Testing Ref/RefMut based peeking at both ends
+    #[test]
+    fn peek() {
+        let mut list: List<i32> = List::new();
+
+        assert!(list.peek_front().is_none());
+        assert!(list.peek_back().is_none());
+        assert!(list.peek_front_mut().is_none());
+        assert!(list.peek_back_mut().is_none());
+
+        list.push_front(1);
+        list.push_back(2);
+
+        // List is now [1, 2]
+        assert_eq!(&*list.peek_front().unwrap(), &1);
+        assert_eq!(&*list.peek_back().unwrap(), &2);
+
+        // Mutate through the returned RefMut
+        *list.peek_front_mut().unwrap() = 10;
+        *list.peek_back_mut().unwrap() = 20;
+
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_back(), Some(20));
+    }
+}
@@ -0,0 +1,269 @@
+use std::ptr;
+
+/*
+ * An Unsafe Queue
+ * - FIFO: push appends at the tail, pop removes from the head
+ * - Keeps an extra raw pointer to the tail node so push stays O(1)
+ *   without needing a second owned reference to the same node.
+ */
+
+type NodeBox<T> = Box<Node<T>>;
+/// Type representing valid states of list nodes.
+type Link<T> = Option<NodeBox<T>>;
+
+/// Struct representing the Linked List
+/// that keeps track of the head and tail pointers.
+pub struct List<T> {
+    head: Link<T>,
+    tail: *mut Node<T>,
+}
+
+/// Struct representing nodes of Linked List
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+}
+
+/// Iterator providing sequential access
+/// to element values while consuming List
+pub struct IntoIter<T>(List<T>);
+
+/// Iterator providing sequential access to
+/// references of list nodes, if available
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+/// Iterator providing sequential access to
+/// mutable references of list nodes in sequence, if available
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
+impl<T> List<T> {
+    /// Create a new List
+    pub fn new() -> Self {
+        List {
+            head: None,
+            tail: ptr::null_mut(),
+        }
+    }
+
+    /// Inserts value at the tail of the Linked List queue
+    pub fn push(&mut self, elem: T) {
+        let mut new_tail: NodeBox<T> = Box::new(Node { elem: elem, next: None });
+
+        // Grab a raw pointer to the new node before it gets moved into the list.
+        let raw_tail: *mut Node<T> = &mut *new_tail;
+
+        if !self.tail.is_null() {
+            // Old tail still exists, point it at the new node.
+            // Safe because self.tail only ever points at a node we own.
+            unsafe {
+                (*self.tail).next = Some(new_tail);
+            }
+        } else {
+            // List was empty, new node becomes the head too.
+            self.head = Some(new_tail);
+        }
+
+        self.tail = raw_tail;
+    }
+
+    /// Remove the first value from the Linked List queue and return it
+    pub fn pop(&mut self) -> Option<T> {
+        self.head.take().map(|node: NodeBox<T>| {
+            self.head = node.next;
+
+            if self.head.is_none() {
+                // List is now empty, invalidate the dangling tail pointer.
+                self.tail = ptr::null_mut();
+            }
+
+            node.elem
+        })
+    }
+
+    /// Retrieve a reference to the head element of the queue
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node: &NodeBox<T>| &node.elem)
+    }
+
+    /// Retrieve a mutable reference to the head element of the queue
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node: &mut NodeBox<T>| &mut node.elem)
+    }
+
+    /// Create an IntoIter struct from List
+    pub fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+
+    /// Create an Iter struct from List
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref() }
+    }
+
+    /// Create an IterMut struct from List
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head.as_deref_mut() }
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        // cur_link is used for list traversal
+        let mut cur_link: Link<T> = self.head.take();
+        // while cur_link contains a valid node
+        while let Some(mut node_box) = cur_link {
+            cur_link = node_box.next.take();
+        }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    /// Get the next value of the iterator
+    fn next(&mut self) -> Option<Self::Item> {
+        // pop returns the node value instead of a reference
+        self.0.pop()
+    }
+}
+
+// Lifetime required here since Iter contains lifetime requirement
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    /// Get next element reference in iterator
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node: &Node<T>| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    /// Get next mutable element reference in iterator
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node: &mut Node<T>| {
+            self.next = node.next.as_deref_mut();
+            &mut node.elem
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+
+    /// Testing basic FIFO behaviour and tail-pointer invalidation
+    #[test]
+    fn basics() {
+        let mut list: List<i32> = List::new();
+
+        // Check if empty list behaves right
+        assert_eq!(list.pop(), None);
+
+        // Insert elements
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        // Check removal, FIFO order
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), Some(2));
+
+        // Push additional data, interleaved with pops
+        list.push(4);
+        list.push(5);
+
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(4));
+
+        // Drain list, tail pointer should be invalidated once it empties out
+        assert_eq!(list.pop(), Some(5));
+        assert_eq!(list.pop(), None);
+
+        // Tail pointer should have been reset, so pushing again starts a fresh chain
+        list.push(6);
+        list.push(7);
+        assert_eq!(list.pop(), Some(6));
+        assert_eq!(list.pop(), Some(7));
+        assert_eq!(list.pop(), None);
+    }
+
+    /// Test peek functionality of List
+    #[test]
+    fn peek() {
+        let mut list: List<i32> = List::new();
+
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.peek_mut(), None);
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.peek_mut(), Some(&mut 1));
+
+        list.peek_mut().map(|value: &mut i32| {
+            *value = 42
+        });
+
+        assert_eq!(list.peek(), Some(&42));
+        assert_eq!(list.pop(), Some(42));
+    }
+
+    /// Testing IntoIter struct functionality
+    #[test]
+    fn into_iter() {
+        let mut list: List<i32> = List::new();
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter: super::IntoIter<i32> = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+
+    /// Testing Iter struct functionality
+    #[test]
+    fn iter() {
+        let mut list: List<i32> = List::new();
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter: super::Iter<i32> = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    /// Testing IterMut struct functionality
+    #[test]
+    fn iter_mut() {
+        let mut list: List<i32> = List::new();
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        let mut iter: super::IterMut<i32> = list.iter_mut();
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next(), None);
+    }
+}
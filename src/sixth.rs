@@ -0,0 +1,481 @@
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::NonNull;
+
+/*
+ * A Production-Quality Doubly-Linked List
+ * - Modeled on the standard library's own `LinkedList<T>`
+ * - Owns its nodes via `Box`, but threads `next`/`prev` as raw
+ *   `NonNull` pointers so both links can be walked in O(1) without
+ *   fighting the borrow checker the way `Rc<RefCell<_>>` does.
+ */
+
+/// Struct representing the Linked List, tracking both ends and its length.
+pub struct LinkedList<T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    /// Tells dropck we logically own boxed `Node<T>`s, so it can
+    /// correctly reason about drop order and variance despite the
+    /// raw pointers above.
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+/// Struct representing nodes of Linked List
+struct Node<T> {
+    next: Option<NonNull<Node<T>>>,
+    prev: Option<NonNull<Node<T>>>,
+    element: T,
+}
+
+/// Iterator providing sequential access
+/// to element values while consuming the list
+pub struct IntoIter<T>(LinkedList<T>);
+
+/// Iterator providing sequential access to
+/// references of list elements, if available
+pub struct Iter<'a, T> {
+    next: Option<NonNull<Node<T>>>,
+    _marker: PhantomData<&'a T>,
+}
+
+/// Iterator providing sequential access to
+/// mutable references of list elements, if available
+pub struct IterMut<'a, T> {
+    next: Option<NonNull<Node<T>>>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<T> Node<T> {
+    /// Box up a new node with no neighbours
+    fn new(element: T) -> NonNull<Node<T>> {
+        let boxed_node: Box<Node<T>> = Box::new(Node {
+            next: None,
+            prev: None,
+            element: element,
+        });
+
+        // Safe because Box::into_raw never returns null.
+        unsafe { NonNull::new_unchecked(Box::into_raw(boxed_node)) }
+    }
+}
+
+impl<T> LinkedList<T> {
+    /// Create a new, empty LinkedList
+    pub fn new() -> Self {
+        LinkedList {
+            head: None,
+            tail: None,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list holds no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts value at the front of the list in O(1)
+    pub fn push_front(&mut self, element: T) {
+        let new_head: NonNull<Node<T>> = Node::new(element);
+
+        unsafe {
+            match self.head {
+                Some(old_head) => {
+                    // Stitch the new node in front of the existing head.
+                    (*old_head.as_ptr()).prev = Some(new_head);
+                    (*new_head.as_ptr()).next = Some(old_head);
+                }
+                None => {
+                    // List was empty, new node becomes head and tail.
+                    self.tail = Some(new_head);
+                }
+            }
+        }
+
+        self.head = Some(new_head);
+        self.len += 1;
+    }
+
+    /// Removes the front element of the list and returns it, in O(1)
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|old_head: NonNull<Node<T>>| {
+            // Reclaim the node so its memory (and element) can move out.
+            let boxed_node: Box<Node<T>> = unsafe { Box::from_raw(old_head.as_ptr()) };
+
+            self.head = boxed_node.next;
+
+            unsafe {
+                match self.head {
+                    Some(new_head) => (*new_head.as_ptr()).prev = None,
+                    None => self.tail = None,
+                }
+            }
+
+            self.len -= 1;
+            boxed_node.element
+        })
+    }
+
+    /// Inserts value at the back of the list in O(1)
+    pub fn push_back(&mut self, element: T) {
+        let new_tail: NonNull<Node<T>> = Node::new(element);
+
+        unsafe {
+            match self.tail {
+                Some(old_tail) => {
+                    // Stitch the new node behind the existing tail.
+                    (*old_tail.as_ptr()).next = Some(new_tail);
+                    (*new_tail.as_ptr()).prev = Some(old_tail);
+                }
+                None => {
+                    // List was empty, new node becomes head and tail.
+                    self.head = Some(new_tail);
+                }
+            }
+        }
+
+        self.tail = Some(new_tail);
+        self.len += 1;
+    }
+
+    /// Removes the back element of the list and returns it, in O(1)
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|old_tail: NonNull<Node<T>>| {
+            let boxed_node: Box<Node<T>> = unsafe { Box::from_raw(old_tail.as_ptr()) };
+
+            self.tail = boxed_node.prev;
+
+            unsafe {
+                match self.tail {
+                    Some(new_tail) => (*new_tail.as_ptr()).next = None,
+                    None => self.head = None,
+                }
+            }
+
+            self.len -= 1;
+            boxed_node.element
+        })
+    }
+
+    /// Retrieve a reference to the front element of the list
+    pub fn front(&self) -> Option<&T> {
+        unsafe { self.head.map(|node: NonNull<Node<T>>| &(*node.as_ptr()).element) }
+    }
+
+    /// Retrieve a mutable reference to the front element of the list
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.head.map(|node: NonNull<Node<T>>| &mut (*node.as_ptr()).element) }
+    }
+
+    /// Retrieve a reference to the back element of the list
+    pub fn back(&self) -> Option<&T> {
+        unsafe { self.tail.map(|node: NonNull<Node<T>>| &(*node.as_ptr()).element) }
+    }
+
+    /// Retrieve a mutable reference to the back element of the list
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.tail.map(|node: NonNull<Node<T>>| &mut (*node.as_ptr()).element) }
+    }
+
+    /// Create an Iter struct from the list
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Create an IterMut struct from the list
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Splits the list into two at the given index, in O(n) to walk to
+    /// the split point but O(1) to relink the two halves. Everything
+    /// from `at` onward is removed from `self` and returned as a new list.
+    ///
+    /// Panics if `at > self.len()`.
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        assert!(at <= self.len, "split index out of bounds");
+
+        if at == 0 {
+            // Everything moves to the returned list.
+            return mem::replace(self, LinkedList::new());
+        }
+
+        if at == self.len {
+            // Nothing to split off.
+            return LinkedList::new();
+        }
+
+        // Walk to the node just before the split point.
+        let mut split_node: NonNull<Node<T>> = self.head.unwrap();
+        for _ in 0..at - 1 {
+            split_node = unsafe { (*split_node.as_ptr()).next.unwrap() };
+        }
+
+        let second_head: Option<NonNull<Node<T>>> = unsafe { (*split_node.as_ptr()).next };
+        let second_len: usize = self.len - at;
+
+        unsafe {
+            (*split_node.as_ptr()).next = None;
+            if let Some(head) = second_head {
+                (*head.as_ptr()).prev = None;
+            }
+        }
+
+        let second_tail: Option<NonNull<Node<T>>> = self.tail;
+        self.tail = Some(split_node);
+        self.len = at;
+
+        LinkedList {
+            head: second_head,
+            tail: second_tail,
+            len: second_len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Moves all elements of `other` onto the back of `self` in O(1),
+    /// leaving `other` empty.
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        match self.tail {
+            None => {
+                // self was empty, just take over other's chain wholesale.
+                mem::swap(self, other);
+            }
+            Some(mut self_tail) => {
+                if let Some(mut other_head) = other.head.take() {
+                    unsafe {
+                        self_tail.as_mut().next = Some(other_head);
+                        other_head.as_mut().prev = Some(self_tail);
+                    }
+
+                    self.tail = other.tail.take();
+                    self.len += other.len;
+                    other.len = 0;
+                }
+            }
+        }
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        // Reclaim every node iteratively so dropping a long list
+        // can't blow the stack the way a recursive Box chain would.
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list: LinkedList<T> = LinkedList::new();
+        for element in iter {
+            list.push_back(element);
+        }
+        list
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node: NonNull<Node<T>>| unsafe {
+            self.next = (*node.as_ptr()).next;
+            &(*node.as_ptr()).element
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node: NonNull<Node<T>>| unsafe {
+            self.next = (*node.as_ptr()).next;
+            &mut (*node.as_ptr()).element
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LinkedList;
+
+    /// Testing push/pop at both ends together with len tracking
+    #[test]
+    fn basics() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_front(0);
+        assert_eq!(list.len(), 3);
+
+        // List is now [0, 1, 2]
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.len(), 2);
+
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.len(), 1);
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+    }
+
+    /// Testing front/back peeking
+    #[test]
+    fn peek() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+
+        list.push_back(1);
+        list.push_back(2);
+
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&2));
+
+        list.front_mut().map(|value: &mut i32| *value = 10);
+        list.back_mut().map(|value: &mut i32| *value = 20);
+
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_back(), Some(20));
+    }
+
+    /// Testing FromIterator and the owned/borrowed/mut-borrowed iterators
+    #[test]
+    fn iterators() {
+        let list: LinkedList<i32> = (1..=3).collect();
+        assert_eq!(list.len(), 3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+        let collected: Vec<i32> = list.iter().copied().collect();
+        assert_eq!(collected, vec![10, 20, 30]);
+
+        let mut into_iter = list.into_iter();
+        assert_eq!(into_iter.next(), Some(10));
+        assert_eq!(into_iter.next(), Some(20));
+        assert_eq!(into_iter.next(), Some(30));
+        assert_eq!(into_iter.next(), None);
+    }
+
+    /// Testing split_off at the start, the end, and in the middle
+    #[test]
+    fn split_off() {
+        let mut list: LinkedList<i32> = (1..=5).collect();
+
+        let tail = list.split_off(2);
+        let left: Vec<i32> = list.iter().copied().collect();
+        let right: Vec<i32> = tail.iter().copied().collect();
+        assert_eq!(left, vec![1, 2]);
+        assert_eq!(right, vec![3, 4, 5]);
+        assert_eq!(list.len(), 2);
+        assert_eq!(tail.len(), 3);
+
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        let all = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(all.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3]);
+
+        let mut list: LinkedList<i32> = (1..=3).collect();
+        let empty = list.split_off(3);
+        assert!(empty.is_empty());
+        assert_eq!(list.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3]);
+    }
+
+    /// Testing append of empty and non-empty lists
+    #[test]
+    fn append() {
+        let mut a: LinkedList<i32> = (1..=2).collect();
+        let mut b: LinkedList<i32> = (3..=4).collect();
+
+        a.append(&mut b);
+        assert_eq!(a.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+        assert_eq!(a.len(), 4);
+        assert!(b.is_empty());
+        assert_eq!(b.len(), 0);
+
+        // Appending an empty list is a no-op.
+        a.append(&mut b);
+        assert_eq!(a.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+
+        // Appending onto an empty list takes over the other list wholesale.
+        let mut empty: LinkedList<i32> = LinkedList::new();
+        let mut c: LinkedList<i32> = (5..=6).collect();
+        empty.append(&mut c);
+        assert_eq!(empty.iter().copied().collect::<Vec<i32>>(), vec![5, 6]);
+        assert!(c.is_empty());
+    }
+}
@@ -48,13 +48,42 @@ impl<T> List<T> {
         self.head.as_ref().map(|node: &NodeRc<T>| &node.elem)
     }
 
-    /// Returns a List of shared references 
+    /// Returns a List of shared references
     /// to all nodes following the calling List's head
     pub fn tail(&self) -> List<T> {
         List {
             head: self.head.as_ref().and_then(|node: &NodeRc<T>| node.next.clone()),
         }
     }
+
+    /// Create an Iter struct from List
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref() }
+    }
+}
+
+/// Iterator providing sequential access to
+/// references of list nodes, if available.
+///
+/// Nodes are shared behind `Rc`, so handing out an owned value or a
+/// unique `&mut T` would break the sharing guarantee; only a
+/// by-reference `Iter` is possible here, unlike the Ok stack's
+/// `IntoIter`/`IterMut` pair.
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+
+    type Item = &'a T;
+
+    /// Get next element reference in iterator
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node: &Node<T>| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
 }
 
 #[cfg(test)]
@@ -83,5 +112,16 @@ mod test {
         assert_eq!(list.head(), None);
 
     }
+
+    #[test]
+    fn iter() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
 }
 